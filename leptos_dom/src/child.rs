@@ -1,6 +1,12 @@
 use cfg_if::cfg_if;
 
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
+    future::Future,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
 
 use leptos_reactive::Scope;
 
@@ -22,6 +28,13 @@ pub enum Child {
     Node(Node),
     /// A list of nodes (text nodes, comments, or elements.)
     Nodes(Vec<Node>),
+    /// A list of nodes paired with a stable key, reconciled by key on update so
+    /// that only the nodes that actually moved are touched in the DOM.
+    KeyedNodes(Vec<(u64, Node)>),
+    /// A child produced by an asynchronous value. Renders as [`Child::Null`] until
+    /// the future resolves, at which point the resolved child is stored in the
+    /// shared cell and the surrounding effect re-runs to swap it in.
+    Async(Rc<RefCell<Option<Child>>>),
 }
 
 impl Child {
@@ -30,7 +43,7 @@ impl Child {
     pub fn as_child_string(&self) -> String {
         match self {
             Child::Null => String::new(),
-            Child::Text(text) => text.to_string(),
+            Child::Text(text) => escape_html(text),
             Child::Fn(f) => {
                 let mut value = (f.borrow_mut())();
                 while let Child::Fn(f) = value {
@@ -40,6 +53,300 @@ impl Child {
             }
             Child::Node(node) => node.to_string(),
             Child::Nodes(nodes) => nodes.iter().cloned().collect(),
+            Child::KeyedNodes(nodes) => nodes.iter().map(|(_, node)| node).cloned().collect(),
+            Child::Async(value) => value
+                .borrow()
+                .as_ref()
+                .map(Child::as_child_string)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Escapes the five HTML-significant characters so that arbitrary text can be
+/// safely interpolated into the server-rendered HTML stream.
+#[cfg(not(any(feature = "hydrate", feature = "csr")))]
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#x27;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Wraps a string of trusted, pre-sanitized HTML so that it is rendered as a
+/// child *without* the entity escaping applied to ordinary [`Child::Text`].
+///
+/// Only use this for content you control; passing user-derived strings here
+/// re-opens the XSS hole that the default escaping closes.
+#[derive(Clone, Debug)]
+pub struct InnerHtml(pub String);
+
+cfg_if! {
+    if #[cfg(any(feature = "hydrate", feature = "csr"))] {
+        use wasm_bindgen::JsCast;
+
+        impl IntoChild for InnerHtml {
+            fn into_child(self, _cx: Scope) -> Child {
+                let template = web_sys::window()
+                    .unwrap()
+                    .document()
+                    .unwrap()
+                    .create_element("template")
+                    .unwrap()
+                    .unchecked_into::<web_sys::HtmlTemplateElement>();
+                template.set_inner_html(&self.0);
+                Child::Node(template.content().unchecked_into())
+            }
+        }
+    } else {
+        impl IntoChild for InnerHtml {
+            fn into_child(self, _cx: Scope) -> Child {
+                // On the server a `Node` is just its HTML string, so emitting the
+                // raw content directly bypasses the escaping in `as_child_string`.
+                Child::Node(self.0)
+            }
+        }
+    }
+}
+
+/// Computes the indices of a longest strictly-increasing subsequence of `seq`.
+///
+/// Used by [`reconcile_keyed`] to find the largest set of existing nodes that
+/// are already in relative order, so that every other node is the minimal set
+/// that has to be moved in the DOM.
+fn longest_increasing_subsequence(seq: &[usize]) -> Vec<usize> {
+    if seq.is_empty() {
+        return Vec::new();
+    }
+    // `tails[k]` is the index in `seq` of the smallest tail of an increasing
+    // subsequence of length `k + 1`; `prev` threads the back-pointers.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut prev = vec![usize::MAX; seq.len()];
+    for i in 0..seq.len() {
+        let mut lo = 0;
+        let mut hi = tails.len();
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if seq[tails[mid]] < seq[i] {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        if lo > 0 {
+            prev[i] = tails[lo - 1];
+        }
+        if lo == tails.len() {
+            tails.push(i);
+        } else {
+            tails[lo] = i;
+        }
+    }
+    let mut result = Vec::with_capacity(tails.len());
+    let mut k = *tails.last().unwrap();
+    while k != usize::MAX {
+        result.push(k);
+        k = prev[k];
+    }
+    result.reverse();
+    result
+}
+
+/// Reconciles a previous keyed node list against an incoming one, reusing the
+/// node for any key that is still present and computing the minimal set of
+/// result positions that must be (re)inserted or moved in the DOM.
+///
+/// Returns the new ordered `(key, node)` list together with the positions in
+/// that list — those outside the longest increasing subsequence of retained
+/// nodes, plus every freshly keyed node — that the renderer has to move.
+pub(crate) fn reconcile_keyed(
+    old: &[(u64, Node)],
+    new: Vec<(u64, Node)>,
+) -> (Vec<(u64, Node)>, Vec<usize>) {
+    let old_index: HashMap<u64, usize> =
+        old.iter().enumerate().map(|(i, (k, _))| (*k, i)).collect();
+
+    let mut result = Vec::with_capacity(new.len());
+    let mut retained_old_pos = Vec::with_capacity(new.len());
+    for (key, node) in new {
+        match old_index.get(&key) {
+            Some(&oi) => {
+                result.push((key, old[oi].1.clone()));
+                retained_old_pos.push(Some(oi));
+            }
+            None => {
+                result.push((key, node));
+                retained_old_pos.push(None);
+            }
+        }
+    }
+
+    // Nodes whose old positions form the LIS are already in order and stay put.
+    let seq: Vec<usize> = retained_old_pos.iter().filter_map(|p| *p).collect();
+    let lis: HashSet<usize> = longest_increasing_subsequence(&seq).into_iter().collect();
+
+    let mut stable = vec![false; result.len()];
+    let mut seq_pos = 0;
+    for (ri, pos) in retained_old_pos.iter().enumerate() {
+        if pos.is_some() {
+            if lis.contains(&seq_pos) {
+                stable[ri] = true;
+            }
+            seq_pos += 1;
+        }
+    }
+
+    let moves = (0..result.len()).filter(|&i| !stable[i]).collect();
+    (result, moves)
+}
+
+/// Realizes a resolved [`Child`] into a single [`Node`] for a keyed list entry.
+#[cfg(not(any(feature = "hydrate", feature = "csr")))]
+fn realize_node(_cx: Scope, child: Child) -> Node {
+    child.as_child_string()
+}
+
+/// Realizes a resolved [`Child`] into a single [`Node`] for a keyed list entry.
+#[cfg(any(feature = "hydrate", feature = "csr"))]
+fn realize_node(cx: Scope, child: Child) -> Node {
+    use wasm_bindgen::JsCast;
+
+    let document = web_sys::window().unwrap().document().unwrap();
+    match child {
+        Child::Node(node) => node,
+        Child::Text(text) => document.create_text_node(&text).unchecked_into(),
+        Child::Nodes(nodes) => {
+            let fragment = document.create_document_fragment();
+            for node in nodes {
+                let _ = fragment.append_child(&node);
+            }
+            fragment.unchecked_into()
+        }
+        Child::KeyedNodes(nodes) => {
+            let fragment = document.create_document_fragment();
+            for (_, node) in nodes {
+                let _ = fragment.append_child(&node);
+            }
+            fragment.unchecked_into()
+        }
+        // An async child renders its resolved value if the shared cell is
+        // already filled, and an empty marker otherwise. The `Fn` wrapper
+        // returned by `Suspend` is re-run by `push_reactive_node` (through
+        // `flatten_child_nodes`), not by this function.
+        Child::Async(cell) => match cell.borrow().clone() {
+            Some(resolved) => realize_node(cx, resolved),
+            None => document.create_comment("").unchecked_into(),
+        },
+        // A bare reactive arm has no stable value here, so it falls back to an
+        // empty marker. `push_child_nodes` routes `Fn` arms to
+        // `push_reactive_node` before they reach this point; a keyed entry whose
+        // value is a reactive function renders as this marker and is not updated.
+        Child::Null | Child::Fn(_) => document.create_comment("").unchecked_into(),
+    }
+}
+
+/// Patches the live keyed list in the DOM against `marker`, a persistent comment
+/// that always sits just after the last list node and gives the fragment a
+/// stable parent even when it is empty.
+///
+/// Removed keys are detached, then the non-stable entries of `reconciled` (those
+/// outside the LIS, plus freshly keyed nodes) are inserted in order relative to
+/// `marker`. On the first pass `previous` is empty, so every node is inserted —
+/// this is also the initial mount.
+#[cfg(any(feature = "hydrate", feature = "csr"))]
+fn patch_keyed_dom(
+    marker: &Node,
+    previous: &[(u64, Node)],
+    reconciled: &[(u64, Node)],
+    moves: &[usize],
+) {
+    let parent = match marker.parent_node() {
+        Some(parent) => parent,
+        None => return,
+    };
+
+    // Detach any node whose key is gone from the new list.
+    let new_keys: HashSet<u64> = reconciled.iter().map(|(key, _)| *key).collect();
+    for (key, node) in previous {
+        if !new_keys.contains(key) {
+            let _ = parent.remove_child(node);
+        }
+    }
+
+    // Walk right-to-left, inserting each non-stable node before the node that
+    // should follow it; `marker` is the anchor for the final position.
+    let move_set: HashSet<usize> = moves.iter().copied().collect();
+    let mut anchor: Node = marker.clone();
+    for (i, (_, node)) in reconciled.iter().enumerate().rev() {
+        if move_set.contains(&i) {
+            let _ = parent.insert_before(node, Some(&anchor));
+        }
+        anchor = node.clone();
+    }
+}
+
+/// Builds a reactive keyed fragment from a function returning `(key, value)`
+/// pairs. On every run the incoming list is reconciled against the previous one
+/// via [`reconcile_keyed`], so matched keys reuse their existing node and only
+/// the minimal set of nodes is moved rather than the whole list re-rendering.
+pub fn keyed<F, K, T>(cx: Scope, items: F) -> Child
+where
+    F: Fn() -> Vec<(K, T)> + 'static,
+    K: Hash + Eq + 'static,
+    T: IntoChild,
+{
+    let realize = move || {
+        items()
+            .into_iter()
+            .map(|(key, value)| {
+                let mut hasher = DefaultHasher::new();
+                key.hash(&mut hasher);
+                (hasher.finish(), realize_node(cx, value.into_child(cx)))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    cfg_if! {
+        if #[cfg(any(feature = "hydrate", feature = "csr"))] {
+            use wasm_bindgen::JsCast;
+
+            // A persistent trailing marker keeps a stable parent for the list and
+            // anchors insertions; it is mounted by returning it as the child, and
+            // the effect (which runs after mount) handles every render including
+            // the first.
+            let marker: Node = web_sys::window()
+                .unwrap()
+                .document()
+                .unwrap()
+                .create_comment("keyed")
+                .unchecked_into();
+            let previous: Rc<RefCell<Vec<(u64, Node)>>> = Rc::new(RefCell::new(Vec::new()));
+            let anchor = marker.clone();
+            leptos_reactive::create_effect(cx, move |_| {
+                let incoming = realize();
+                let (reconciled, moves) = {
+                    let prev = previous.borrow();
+                    reconcile_keyed(&prev, incoming)
+                };
+                {
+                    let prev = previous.borrow();
+                    patch_keyed_dom(&anchor, &prev, &reconciled, &moves);
+                }
+                *previous.borrow_mut() = reconciled;
+            });
+            Child::Node(marker)
+        } else {
+            // No DOM on the server: render the list once as a static fragment.
+            let (reconciled, _) = reconcile_keyed(&[], realize());
+            Child::KeyedNodes(reconciled)
         }
     }
 }
@@ -52,6 +359,8 @@ impl std::fmt::Debug for Child {
             Self::Fn(_) => f.debug_tuple("Fn").finish(),
             Self::Node(arg0) => f.debug_tuple("Node").field(arg0).finish(),
             Self::Nodes(arg0) => f.debug_tuple("Nodes").field(arg0).finish(),
+            Self::KeyedNodes(arg0) => f.debug_tuple("KeyedNodes").field(arg0).finish(),
+            Self::Async(_) => f.debug_tuple("Async").finish(),
         }
     }
 }
@@ -63,6 +372,8 @@ impl PartialEq for Child {
             (Self::Fn(l0), Self::Fn(r0)) => std::ptr::eq(l0, r0),
             (Self::Node(l0), Self::Node(r0)) => l0 == r0,
             (Self::Nodes(l0), Self::Nodes(r0)) => l0 == r0,
+            (Self::KeyedNodes(l0), Self::KeyedNodes(r0)) => l0 == r0,
+            (Self::Async(l0), Self::Async(r0)) => Rc::ptr_eq(l0, r0),
             _ => core::mem::discriminant(self) == core::mem::discriminant(other),
         }
     }
@@ -103,6 +414,183 @@ where
     }
 }
 
+/// Wraps a future so it can be used as a child.
+///
+/// A bare `Future` can't implement [`IntoChild`] — that would be a second
+/// blanket impl over an unconstrained type parameter and would collide with the
+/// closure impl above (a type could be both `FnMut` and `Future`, which
+/// coherence can't rule out). Wrapping in `Suspend`, like [`InnerHtml`] does for
+/// raw HTML, gives the async path a concrete type to key off instead.
+pub struct Suspend<Fut>(pub Fut);
+
+impl<T, Fut> IntoChild for Suspend<Fut>
+where
+    Fut: Future<Output = T> + 'static,
+    T: IntoChild,
+{
+    fn into_child(self, cx: Scope) -> Child {
+        let Suspend(fut) = self;
+        // Render nothing until the future resolves; the cell is then filled and
+        // the surrounding effect/`Suspense` re-runs to swap the resolved node in.
+        let value = Rc::new(RefCell::new(None));
+
+        cfg_if! {
+            if #[cfg(any(feature = "hydrate", feature = "csr"))] {
+                let (read, write) = leptos_reactive::create_signal(cx, 0usize);
+                let cell = value.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let resolved = fut.await.into_child(cx);
+                    *cell.borrow_mut() = Some(resolved);
+                    write.update(|n| *n += 1);
+                });
+                (move || {
+                    read.get();
+                    Child::Async(value.clone())
+                })
+                .into_child(cx)
+            } else {
+                // On the server we must not `block_on` — the request handler is
+                // already inside the async runtime. Most SSR futures are fronted
+                // by an already-resolved resource, so poll once with a no-op
+                // waker and render the value if it is ready; otherwise leave a
+                // trace and render nothing rather than deadlocking.
+                let _ = &value;
+                let mut fut = Box::pin(fut);
+                let waker = noop_waker();
+                let mut ctx = std::task::Context::from_waker(&waker);
+                match fut.as_mut().poll(&mut ctx) {
+                    std::task::Poll::Ready(resolved) => resolved.into_child(cx),
+                    std::task::Poll::Pending => {
+                        eprintln!(
+                            "async child was not resolved synchronously during SSR; \
+                             wrap it in a `Suspense` to await it"
+                        );
+                        Child::Null
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A waker that does nothing, used to poll an already-resolved SSR future once
+/// without a runtime.
+#[cfg(not(any(feature = "hydrate", feature = "csr")))]
+fn noop_waker() -> std::task::Waker {
+    use std::task::{RawWaker, RawWakerVTable, Waker};
+
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(
+        |_| RawWaker::new(std::ptr::null(), &VTABLE),
+        |_| {},
+        |_| {},
+        |_| {},
+    );
+    // Safety: the vtable's clone/wake/drop are all no-ops over a null pointer.
+    unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+}
+
+/// A boxed error collected by an [`ErrorBoundary`].
+pub type BoxedError = Rc<dyn std::error::Error>;
+
+/// Collects the errors reported by fallible children rendered beneath it.
+///
+/// An `ErrorBoundary` is placed into the reactive [`Scope`] context by an
+/// error-boundary component; [`IntoChild for Result`](IntoChild) looks it up and
+/// registers any `Err` it encounters instead of panicking or leaking the value.
+#[derive(Clone, Default)]
+pub struct ErrorBoundary {
+    errors: Rc<RefCell<Vec<BoxedError>>>,
+}
+
+impl ErrorBoundary {
+    /// Creates an empty boundary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an error reported by a child, ignoring one whose message is
+    /// already present so that a child re-evaluating to the same `Err` on every
+    /// reactive run doesn't grow the list without bound.
+    pub fn register(&self, error: BoxedError) {
+        let mut errors = self.errors.borrow_mut();
+        let message = error.to_string();
+        if !errors.iter().any(|existing| existing.to_string() == message) {
+            errors.push(error);
+        }
+    }
+
+    /// Clears every collected error. A boundary component calls this at the start
+    /// of each render pass so stale errors from a previous run don't linger.
+    pub fn clear(&self) {
+        self.errors.borrow_mut().clear();
+    }
+
+    /// Returns a snapshot of the errors reported so far.
+    pub fn errors(&self) -> Vec<BoxedError> {
+        self.errors.borrow().clone()
+    }
+
+    /// Renders the collected errors as a child so a boundary component can show
+    /// them in place of the failed content. Returns [`Child::Null`] while no
+    /// error has been reported.
+    pub fn render(&self) -> Child {
+        let errors = self.errors.borrow();
+        if errors.is_empty() {
+            Child::Null
+        } else {
+            Child::Text(
+                errors
+                    .iter()
+                    .map(|error| error.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            )
+        }
+    }
+}
+
+impl IntoChild for ErrorBoundary {
+    fn into_child(self, _cx: Scope) -> Child {
+        self.render()
+    }
+}
+
+/// Logs an error that reached [`IntoChild for Result`](IntoChild) with no
+/// [`ErrorBoundary`] in context, so a failed child leaves a trace instead of
+/// vanishing silently.
+fn report_unhandled_error(error: &dyn std::error::Error) {
+    cfg_if! {
+        if #[cfg(any(feature = "hydrate", feature = "csr"))] {
+            web_sys::console::error_1(
+                &format!("rendered a `Result::Err` with no ErrorBoundary in scope: {error}").into(),
+            );
+        } else {
+            eprintln!("rendered a `Result::Err` with no ErrorBoundary in scope: {error}");
+        }
+    }
+}
+
+impl<T, E> IntoChild for Result<T, E>
+where
+    T: IntoChild,
+    E: std::error::Error + 'static,
+{
+    fn into_child(self, cx: Scope) -> Child {
+        match self {
+            Ok(value) => value.into_child(cx),
+            Err(error) => {
+                // Report to the nearest boundary if one is registered; otherwise
+                // leave a diagnostic rather than vanishing silently.
+                match cx.use_context::<ErrorBoundary>() {
+                    Some(boundary) => boundary.register(Rc::new(error)),
+                    None => report_unhandled_error(&error),
+                }
+                Child::Null
+            }
+        }
+    }
+}
+
 impl<T> IntoChild for Option<T>
 where
     T: IntoChild,
@@ -121,6 +609,161 @@ impl IntoChild for Vec<Node> {
     }
 }
 
+impl<K, T> IntoChild for Vec<(K, T)>
+where
+    K: Hash + Eq + 'static,
+    T: IntoChild,
+{
+    fn into_child(self, cx: Scope) -> Child {
+        let keyed = self
+            .into_iter()
+            .map(|(key, value)| {
+                let mut hasher = DefaultHasher::new();
+                key.hash(&mut hasher);
+                (hasher.finish(), realize_node(cx, value.into_child(cx)))
+            })
+            .collect();
+        Child::KeyedNodes(keyed)
+    }
+}
+
+/// Flattens a resolved [`Child`] into `out` as a node list: nested
+/// `Nodes`/`KeyedNodes` are spliced in, `Null` contributes nothing, a reactive
+/// `Fn` arm is kept live (its node is driven by an effect so updates still
+/// apply), and everything else realizes to one node.
+fn push_child_nodes(cx: Scope, child: Child, out: &mut Vec<Node>) {
+    match child {
+        Child::Null => {}
+        Child::Nodes(nodes) => out.extend(nodes),
+        Child::KeyedNodes(nodes) => out.extend(nodes.into_iter().map(|(_, node)| node)),
+        Child::Fn(f) => push_reactive_node(cx, f, out),
+        other => out.push(realize_node(cx, other)),
+    }
+}
+
+/// Expands a resolved (non-`Fn`) child into individual nodes, flattening node
+/// lists rather than collapsing them into a `DocumentFragment` — a fragment is
+/// emptied the moment it is inserted, which would lose the handles needed to
+/// update it again later.
+#[cfg(any(feature = "hydrate", feature = "csr"))]
+fn flatten_child_nodes(cx: Scope, child: Child, out: &mut Vec<Node>) {
+    match child {
+        Child::Null => {}
+        Child::Nodes(nodes) => out.extend(nodes),
+        Child::KeyedNodes(nodes) => out.extend(nodes.into_iter().map(|(_, node)| node)),
+        Child::Fn(f) => {
+            let mut value = (f.borrow_mut())();
+            while let Child::Fn(inner) = value {
+                value = (inner.borrow_mut())();
+            }
+            flatten_child_nodes(cx, value, out);
+        }
+        Child::Async(cell) => {
+            if let Some(resolved) = cell.borrow().clone() {
+                flatten_child_nodes(cx, resolved, out);
+            }
+        }
+        other => out.push(realize_node(cx, other)),
+    }
+}
+
+/// Pushes a node backed by a reactive `Fn` child, preserving reactivity inside a
+/// flattened fragment. On the client a persistent marker anchors the content and
+/// an effect re-renders the nodes before it whenever the function's dependencies
+/// change; on the server it resolves once.
+#[cfg(any(feature = "hydrate", feature = "csr"))]
+fn push_reactive_node(
+    cx: Scope,
+    f: Rc<RefCell<dyn FnMut() -> Child>>,
+    out: &mut Vec<Node>,
+) {
+    use wasm_bindgen::JsCast;
+
+    let document = web_sys::window().unwrap().document().unwrap();
+    // The marker is mounted with the fragment and stays put, so the parent is
+    // always reachable even when the reactive value renders to nothing.
+    let marker: Node = document.create_comment("dyn").unchecked_into();
+    out.push(marker.clone());
+
+    let rendered: Rc<RefCell<Vec<Node>>> = Rc::new(RefCell::new(Vec::new()));
+    leptos_reactive::create_effect(cx, move |_| {
+        let mut value = (f.borrow_mut())();
+        while let Child::Fn(inner) = value {
+            value = (inner.borrow_mut())();
+        }
+        let mut next = Vec::new();
+        flatten_child_nodes(cx, value, &mut next);
+
+        if let Some(parent) = marker.parent_node() {
+            for node in rendered.borrow().iter() {
+                let _ = parent.remove_child(node);
+            }
+            for node in &next {
+                let _ = parent.insert_before(node, Some(&marker));
+            }
+        }
+        *rendered.borrow_mut() = next;
+    });
+}
+
+/// Server counterpart of [`push_reactive_node`]: there is no DOM to update, so
+/// the function is resolved to its current value once.
+#[cfg(not(any(feature = "hydrate", feature = "csr")))]
+fn push_reactive_node(
+    cx: Scope,
+    f: Rc<RefCell<dyn FnMut() -> Child>>,
+    out: &mut Vec<Node>,
+) {
+    let mut value = (f.borrow_mut())();
+    while let Child::Fn(inner) = value {
+        value = (inner.borrow_mut())();
+    }
+    push_child_nodes(cx, value, out);
+}
+
+macro_rules! tuple_child {
+    ($($ty:ident),+) => {
+        impl<$($ty),+> IntoChild for ($($ty,)+)
+        where
+            $($ty: IntoChild),+
+        {
+            fn into_child(self, cx: Scope) -> Child {
+                #[allow(non_snake_case)]
+                let ($($ty,)+) = self;
+                let mut nodes = Vec::new();
+                $(push_child_nodes(cx, $ty.into_child(cx), &mut nodes);)+
+                Child::Nodes(nodes)
+            }
+        }
+    };
+}
+
+tuple_child!(A);
+tuple_child!(A, B);
+tuple_child!(A, B, C);
+tuple_child!(A, B, C, D);
+tuple_child!(A, B, C, D, E);
+tuple_child!(A, B, C, D, E, F);
+tuple_child!(A, B, C, D, E, F, G);
+tuple_child!(A, B, C, D, E, F, G, H);
+tuple_child!(A, B, C, D, E, F, G, H, I);
+tuple_child!(A, B, C, D, E, F, G, H, I, J);
+tuple_child!(A, B, C, D, E, F, G, H, I, J, K);
+tuple_child!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+impl<T, const N: usize> IntoChild for [T; N]
+where
+    T: IntoChild,
+{
+    fn into_child(self, cx: Scope) -> Child {
+        let mut nodes = Vec::new();
+        for item in self {
+            push_child_nodes(cx, item.into_child(cx), &mut nodes);
+        }
+        Child::Nodes(nodes)
+    }
+}
+
 macro_rules! child_type {
     ($child_type:ty) => {
         impl IntoChild for $child_type {